@@ -0,0 +1,105 @@
+//! Helpers for the [EIP-1559] fee market.
+//!
+//! [EIP-1559]: https://eips.ethereum.org/EIPS/eip-1559
+
+use crate::U256;
+
+/// The bound on how much the gas limit is allowed to deviate from the gas
+/// target, per [EIP-1559].
+///
+/// [EIP-1559]: https://eips.ethereum.org/EIPS/eip-1559
+pub const ELASTICITY_MULTIPLIER: u64 = 2;
+
+/// The bound on how much the base fee can change between two consecutive
+/// blocks, per [EIP-1559].
+///
+/// [EIP-1559]: https://eips.ethereum.org/EIPS/eip-1559
+pub const BASE_FEE_MAX_CHANGE_DENOMINATOR: U256 = U256::from_limbs([8, 0, 0, 0]);
+
+/// Computes the base fee per gas of the block that follows a parent block
+/// with the provided base fee, gas used, and gas limit, per [EIP-1559].
+///
+/// [EIP-1559]: https://eips.ethereum.org/EIPS/eip-1559
+pub fn calculate_next_base_fee(
+    parent_base_fee: U256,
+    parent_gas_used: u64,
+    parent_gas_limit: u64,
+) -> U256 {
+    let gas_target = parent_gas_limit / ELASTICITY_MULTIPLIER;
+
+    if gas_target == 0 {
+        return parent_base_fee;
+    }
+
+    match parent_gas_used.cmp(&gas_target) {
+        std::cmp::Ordering::Equal => parent_base_fee,
+        std::cmp::Ordering::Greater => {
+            let gas_used_delta = U256::from(parent_gas_used - gas_target);
+
+            let base_fee_delta = std::cmp::max(
+                U256::from(1),
+                parent_base_fee * gas_used_delta
+                    / U256::from(gas_target)
+                    / BASE_FEE_MAX_CHANGE_DENOMINATOR,
+            );
+
+            parent_base_fee + base_fee_delta
+        }
+        std::cmp::Ordering::Less => {
+            let gas_used_delta = U256::from(gas_target - parent_gas_used);
+
+            let base_fee_delta = parent_base_fee * gas_used_delta
+                / U256::from(gas_target)
+                / BASE_FEE_MAX_CHANGE_DENOMINATOR;
+
+            parent_base_fee.saturating_sub(base_fee_delta)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gas_used_equals_target_keeps_base_fee_unchanged() {
+        let base_fee = calculate_next_base_fee(U256::from(100), 10_000_000, 20_000_000);
+        assert_eq!(base_fee, U256::from(100));
+    }
+
+    #[test]
+    fn gas_used_above_target_increases_base_fee() {
+        let base_fee = calculate_next_base_fee(U256::from(100), 20_000_000, 20_000_000);
+        assert!(base_fee > U256::from(100));
+    }
+
+    #[test]
+    fn gas_used_below_target_decreases_base_fee() {
+        let base_fee = calculate_next_base_fee(U256::from(100), 0, 20_000_000);
+        assert!(base_fee < U256::from(100));
+    }
+
+    #[test]
+    fn zero_gas_limit_does_not_panic() {
+        // gas_target = parent_gas_limit / ELASTICITY_MULTIPLIER = 0, which would
+        // divide by zero in the `Greater`/`Less` branches without the guard.
+        let base_fee = calculate_next_base_fee(U256::from(100), 0, 0);
+        assert_eq!(base_fee, U256::from(100));
+    }
+
+    #[test]
+    fn gas_limit_of_one_does_not_panic() {
+        // gas_target = 1 / 2 = 0 (integer division), same hazard as a gas limit
+        // of 0.
+        let base_fee = calculate_next_base_fee(U256::from(100), 1, 1);
+        assert_eq!(base_fee, U256::from(100));
+    }
+
+    #[test]
+    fn base_fee_never_drops_below_zero() {
+        // The decrease is at most 1/8 of the parent base fee, so a base fee
+        // of 1 yields a delta of 1 * 10_000_000 / 10_000_000 / 8 = 0.
+        let base_fee = calculate_next_base_fee(U256::from(1), 0, 20_000_000);
+        assert_eq!(base_fee, U256::from(1));
+    }
+}
@@ -0,0 +1,334 @@
+//! Types for the EIP-4844 blob sidecar, i.e. the "network form" of a blob
+//! transaction as defined by [EIP-4844].
+//!
+//! The consensus form of an EIP-4844 transaction (see
+//! [`transaction::signed::Eip4844`](crate::transaction::signed::Eip4844))
+//! only carries the versioned hashes of its blobs. The network form wraps the
+//! consensus transaction together with the blobs themselves plus their KZG
+//! commitments and proofs, so that it can be validated and gossiped without
+//! consulting a separate data availability layer.
+//!
+//! [EIP-4844]: https://eips.ethereum.org/EIPS/eip-4844
+
+use alloy_rlp::{BufMut, Decodable, Encodable, RlpDecodable, RlpEncodable};
+use sha2::{Digest, Sha256};
+
+use crate::{transaction::signed, B256};
+
+/// Number of field elements in a single blob.
+pub const FIELD_ELEMENTS_PER_BLOB: usize = 4096;
+/// Size in bytes of a single field element.
+pub const BYTES_PER_FIELD_ELEMENT: usize = 32;
+/// Size in bytes of a single blob.
+pub const BYTES_PER_BLOB: usize = FIELD_ELEMENTS_PER_BLOB * BYTES_PER_FIELD_ELEMENT;
+/// Size in bytes of a KZG commitment or proof.
+pub const BYTES_PER_COMMITMENT: usize = 48;
+
+/// The version byte prepended to the SHA-256 digest of a KZG commitment to
+/// derive its versioned hash, per [EIP-4844].
+///
+/// [EIP-4844]: https://eips.ethereum.org/EIPS/eip-4844
+pub const VERSIONED_HASH_VERSION_KZG: u8 = 0x01;
+
+/// A single blob of data, as defined by [EIP-4844].
+///
+/// [EIP-4844]: https://eips.ethereum.org/EIPS/eip-4844
+pub type Blob = [u8; BYTES_PER_BLOB];
+/// A KZG commitment to a [`Blob`].
+pub type KzgCommitment = [u8; BYTES_PER_COMMITMENT];
+/// A KZG proof of a [`Blob`]'s evaluation at a point.
+pub type KzgProof = [u8; BYTES_PER_COMMITMENT];
+
+/// Error that occurs when validating a [`BlobSidecar`].
+#[derive(Debug, thiserror::Error)]
+pub enum BlobSidecarError {
+    /// The number of blobs, commitments, and proofs did not match.
+    #[error(
+        "Number of blobs ({num_blobs}), commitments ({num_commitments}) and proofs \
+         ({num_proofs}) must be equal, and must match the transaction's blob hashes \
+         ({num_blob_hashes})"
+    )]
+    LengthMismatch {
+        num_blobs: usize,
+        num_commitments: usize,
+        num_proofs: usize,
+        num_blob_hashes: usize,
+    },
+    /// The versioned hash derived from a commitment did not match the
+    /// transaction's corresponding blob hash.
+    #[error("Blob at index {index} does not match its versioned hash")]
+    VersionedHashMismatch { index: usize },
+    /// The KZG proof for a blob failed to verify.
+    #[error("KZG proof for blob at index {index} failed to verify")]
+    InvalidKzgProof { index: usize },
+    /// The underlying `c-kzg` library returned an error.
+    #[error(transparent)]
+    Kzg(#[from] c_kzg::Error),
+}
+
+/// The sidecar data of an EIP-4844 blob transaction: the blobs themselves,
+/// along with their KZG commitments and proofs.
+#[derive(Clone, Debug, Eq, PartialEq, RlpDecodable, RlpEncodable)]
+pub struct BlobSidecar {
+    /// The blobs.
+    pub blobs: Vec<Blob>,
+    /// The KZG commitments corresponding to `blobs`.
+    pub commitments: Vec<KzgCommitment>,
+    /// The KZG proofs corresponding to `blobs` and `commitments`.
+    pub proofs: Vec<KzgProof>,
+}
+
+impl BlobSidecar {
+    /// Derives the versioned hash of a single KZG commitment.
+    pub fn versioned_hash(commitment: &KzgCommitment) -> B256 {
+        let mut hash = Sha256::digest(commitment);
+        hash[0] = VERSIONED_HASH_VERSION_KZG;
+
+        B256::from_slice(&hash)
+    }
+
+    /// Verifies that this sidecar matches `blob_hashes` and that every blob's
+    /// KZG proof is valid under `trusted_setup`.
+    pub fn verify(
+        &self,
+        blob_hashes: &[B256],
+        trusted_setup: &c_kzg::KzgSettings,
+    ) -> Result<(), BlobSidecarError> {
+        if self.blobs.len() != self.commitments.len()
+            || self.blobs.len() != self.proofs.len()
+            || self.blobs.len() != blob_hashes.len()
+        {
+            return Err(BlobSidecarError::LengthMismatch {
+                num_blobs: self.blobs.len(),
+                num_commitments: self.commitments.len(),
+                num_proofs: self.proofs.len(),
+                num_blob_hashes: blob_hashes.len(),
+            });
+        }
+
+        for (index, ((blob, commitment), proof)) in self
+            .blobs
+            .iter()
+            .zip(self.commitments.iter())
+            .zip(self.proofs.iter())
+            .enumerate()
+        {
+            if Self::versioned_hash(commitment) != blob_hashes[index] {
+                return Err(BlobSidecarError::VersionedHashMismatch { index });
+            }
+
+            let valid = c_kzg::KzgProof::verify_blob_kzg_proof(
+                &c_kzg::Blob::from_bytes(blob)?,
+                &c_kzg::Bytes48::from_bytes(commitment)?,
+                &c_kzg::Bytes48::from_bytes(proof)?,
+                trusted_setup,
+            )?;
+
+            if !valid {
+                return Err(BlobSidecarError::InvalidKzgProof { index });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The network form of an EIP-4844 transaction: the consensus transaction
+/// together with its [`BlobSidecar`].
+///
+/// Encodes/decodes as `0x03 || rlp([tx_payload, blobs, commitments,
+/// proofs])`, distinct from the consensus form produced by
+/// [`signed::Eip4844::hash`], which does not include the sidecar.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Eip4844WithSidecar {
+    /// The consensus-form transaction.
+    pub transaction: signed::Eip4844,
+    /// The blobs, commitments and proofs belonging to the transaction.
+    pub sidecar: BlobSidecar,
+}
+
+impl Eip4844WithSidecar {
+    /// Validates the sidecar against the transaction's blob hashes and the
+    /// provided KZG trusted setup.
+    pub fn verify(&self, trusted_setup: &c_kzg::KzgSettings) -> Result<(), BlobSidecarError> {
+        self.sidecar
+            .verify(&self.transaction.blob_hashes, trusted_setup)
+    }
+}
+
+impl Encodable for Eip4844WithSidecar {
+    fn length(&self) -> usize {
+        let payload_length = self.transaction.length()
+            + self.sidecar.blobs.length()
+            + self.sidecar.commitments.length()
+            + self.sidecar.proofs.length();
+
+        1 + alloy_rlp::length_of_length(payload_length) + payload_length
+    }
+
+    fn encode(&self, out: &mut dyn BufMut) {
+        out.put_u8(0x03);
+
+        let payload_length = self.transaction.length()
+            + self.sidecar.blobs.length()
+            + self.sidecar.commitments.length()
+            + self.sidecar.proofs.length();
+
+        alloy_rlp::Header {
+            list: true,
+            payload_length,
+        }
+        .encode(out);
+
+        self.transaction.encode(out);
+        self.sidecar.blobs.encode(out);
+        self.sidecar.commitments.encode(out);
+        self.sidecar.proofs.encode(out);
+    }
+}
+
+impl Decodable for Eip4844WithSidecar {
+    fn decode(buf: &mut &[u8]) -> alloy_rlp::Result<Self> {
+        let first = *buf.first().ok_or(alloy_rlp::Error::InputTooShort)?;
+        if first != 0x03 {
+            return Err(alloy_rlp::Error::Custom(
+                "Not an EIP-4844 typed transaction envelope",
+            ));
+        }
+        *buf = &buf[1..];
+
+        let alloy_rlp::Header {
+            list,
+            payload_length,
+        } = alloy_rlp::Header::decode(buf)?;
+        if !list {
+            return Err(alloy_rlp::Error::UnexpectedString);
+        }
+
+        let payload_view = &mut buf
+            .get(..payload_length)
+            .ok_or(alloy_rlp::Error::InputTooShort)?;
+
+        let transaction = signed::Eip4844::decode(payload_view)?;
+        let blobs = Vec::<Blob>::decode(payload_view)?;
+        let commitments = Vec::<KzgCommitment>::decode(payload_view)?;
+        let proofs = Vec::<KzgProof>::decode(payload_view)?;
+
+        if !payload_view.is_empty() {
+            return Err(alloy_rlp::Error::ListLengthMismatch {
+                expected: payload_length,
+                got: payload_length - payload_view.len(),
+            });
+        }
+
+        *buf = &buf[payload_length..];
+
+        Ok(Self {
+            transaction,
+            sidecar: BlobSidecar {
+                blobs,
+                commitments,
+                proofs,
+            },
+        })
+    }
+}
+
+impl Eip4844WithSidecar {
+    /// Computes the typed-envelope hash of the wrapped consensus transaction.
+    ///
+    /// This is identical to [`signed::Eip4844::hash`] and does not cover the
+    /// sidecar, matching [EIP-4844]'s separation of consensus and network
+    /// forms.
+    ///
+    /// [EIP-4844]: https://eips.ethereum.org/EIPS/eip-4844
+    pub fn hash(&self) -> B256 {
+        *self.transaction.hash()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{str::FromStr, sync::OnceLock};
+
+    use revm_primitives::b256;
+
+    use super::*;
+    use crate::{signature, transaction, Address, Bytes, U256};
+
+    fn dummy_sidecar() -> BlobSidecar {
+        BlobSidecar {
+            blobs: vec![[1u8; BYTES_PER_BLOB]],
+            commitments: vec![[2u8; BYTES_PER_COMMITMENT]],
+            proofs: vec![[3u8; BYTES_PER_COMMITMENT]],
+        }
+    }
+
+    fn dummy_transaction() -> signed::Eip4844 {
+        let request = transaction::request::Eip4844 {
+            chain_id: 1,
+            nonce: 0,
+            max_priority_fee_per_gas: U256::from(1),
+            max_fee_per_gas: U256::from(1),
+            gas_limit: 0x33450,
+            to: Address::from_str("0xffb38a7a99e3e2335be83fc74b7faa19d5531243").unwrap(),
+            value: U256::ZERO,
+            input: Bytes::default(),
+            access_list: Vec::new(),
+            max_fee_per_blob_gas: U256::from(1),
+            blob_hashes: vec![b256!(
+                "01b0a4cdd5f55589f5c5b4d46c76704bb6ce95c0a8c09f77f197a57808dded28"
+            )],
+        };
+
+        let signature = signature::Fakeable::fake(Address::ZERO);
+
+        signed::Eip4844 {
+            chain_id: request.chain_id,
+            nonce: request.nonce,
+            max_priority_fee_per_gas: request.max_priority_fee_per_gas,
+            max_fee_per_gas: request.max_fee_per_gas,
+            gas_limit: request.gas_limit,
+            to: request.to,
+            value: request.value,
+            input: request.input,
+            access_list: request.access_list.into(),
+            max_fee_per_blob_gas: request.max_fee_per_blob_gas,
+            blob_hashes: request.blob_hashes,
+            signature,
+            hash: OnceLock::new(),
+        }
+    }
+
+    #[test]
+    fn versioned_hash_has_kzg_version_byte() {
+        let commitment = [4u8; BYTES_PER_COMMITMENT];
+        let hash = BlobSidecar::versioned_hash(&commitment);
+
+        assert_eq!(hash[0], VERSIONED_HASH_VERSION_KZG);
+    }
+
+    #[test]
+    fn blob_sidecar_rlp_roundtrip() {
+        let sidecar = dummy_sidecar();
+        let encoded = alloy_rlp::encode(&sidecar);
+
+        assert_eq!(sidecar, BlobSidecar::decode(&mut encoded.as_slice()).unwrap());
+    }
+
+    #[test]
+    fn eip4844_with_sidecar_rlp_roundtrip() {
+        let with_sidecar = Eip4844WithSidecar {
+            transaction: dummy_transaction(),
+            sidecar: dummy_sidecar(),
+        };
+
+        let encoded = alloy_rlp::encode(&with_sidecar);
+        assert_eq!(0x03, encoded[0]);
+
+        let decoded = Eip4844WithSidecar::decode(&mut encoded.as_slice()).unwrap();
+        assert_eq!(with_sidecar.transaction, decoded.transaction);
+        assert_eq!(with_sidecar.sidecar, decoded.sidecar);
+        assert_eq!(with_sidecar.hash(), decoded.hash());
+    }
+}
@@ -47,6 +47,7 @@ impl Eip155 {
             input: self.input,
             signature,
             hash: OnceLock::new(),
+            sender: OnceLock::new(),
             is_fake: false,
         })
     }
@@ -65,6 +66,7 @@ impl Eip155 {
             input: self.input,
             signature,
             hash: OnceLock::new(),
+            sender: OnceLock::new(),
             is_fake: true,
         }
     }
@@ -0,0 +1,131 @@
+use std::sync::OnceLock;
+
+use alloy_rlp::{BufMut, Encodable};
+use revm_primitives::keccak256;
+
+use crate::{
+    access_list::AccessList,
+    signature::{self, Fakeable, SignatureError},
+    transaction::{self, TxKind},
+    utils::envelop_bytes,
+    Address, Bytes, B256, U256,
+};
+
+/// The [EIP-2718] transaction type identifier of an EIP-2930 transaction.
+///
+/// [EIP-2718]: https://eips.ethereum.org/EIPS/eip-2718
+pub const TYPE: u8 = 1;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Eip2930 {
+    // The order of these fields determines encoding order.
+    pub chain_id: u64,
+    pub nonce: u64,
+    pub gas_price: U256,
+    pub gas_limit: u64,
+    pub kind: TxKind,
+    pub value: U256,
+    pub input: Bytes,
+    pub access_list: AccessList,
+}
+
+impl Eip2930 {
+    /// Computes the hash of the transaction's signing payload, i.e. the
+    /// EIP-2718 typed envelope without the signature fields.
+    pub fn hash(&self) -> B256 {
+        keccak256(envelop_bytes(TYPE, &alloy_rlp::encode(self)))
+    }
+
+    /// Signs the transaction with the provided secret key.
+    pub fn sign(
+        self,
+        secret_key: &k256::SecretKey,
+    ) -> Result<transaction::signed::Eip2930, SignatureError> {
+        let hash = self.hash();
+
+        let signature = signature::SignatureWithYParity::new(hash, secret_key)?;
+        let signature = Fakeable::recover(signature, hash)?;
+
+        Ok(transaction::signed::Eip2930 {
+            chain_id: self.chain_id,
+            nonce: self.nonce,
+            gas_price: self.gas_price,
+            gas_limit: self.gas_limit,
+            kind: self.kind,
+            value: self.value,
+            input: self.input,
+            access_list: self.access_list,
+            signature,
+            hash: OnceLock::new(),
+        })
+    }
+
+    /// Creates a fake signature for an impersonated account, so that test
+    /// setups can impersonate senders of access-list transactions too.
+    pub fn fake_sign(self, address: &Address) -> transaction::signed::Eip2930 {
+        let signature = Fakeable::fake(*address);
+
+        transaction::signed::Eip2930 {
+            chain_id: self.chain_id,
+            nonce: self.nonce,
+            gas_price: self.gas_price,
+            gas_limit: self.gas_limit,
+            kind: self.kind,
+            value: self.value,
+            input: self.input,
+            access_list: self.access_list,
+            signature,
+            hash: OnceLock::new(),
+        }
+    }
+
+    fn rlp_payload_length(&self) -> usize {
+        self.chain_id.length()
+            + self.nonce.length()
+            + self.gas_price.length()
+            + self.gas_limit.length()
+            + self.kind.length()
+            + self.value.length()
+            + self.input.length()
+            + self.access_list.length()
+    }
+}
+
+impl Encodable for Eip2930 {
+    fn length(&self) -> usize {
+        let payload_length = self.rlp_payload_length();
+        payload_length + alloy_rlp::length_of_length(payload_length)
+    }
+
+    fn encode(&self, out: &mut dyn BufMut) {
+        alloy_rlp::Header {
+            list: true,
+            payload_length: self.rlp_payload_length(),
+        }
+        .encode(out);
+
+        self.chain_id.encode(out);
+        self.nonce.encode(out);
+        self.gas_price.encode(out);
+        self.gas_limit.encode(out);
+        self.kind.encode(out);
+        self.value.encode(out);
+        self.input.encode(out);
+        self.access_list.encode(out);
+    }
+}
+
+impl From<&transaction::signed::Eip2930> for Eip2930 {
+    fn from(tx: &transaction::signed::Eip2930) -> Self {
+        Self {
+            chain_id: tx.chain_id,
+            nonce: tx.nonce,
+            gas_price: tx.gas_price,
+            gas_limit: tx.gas_limit,
+            kind: tx.kind,
+            value: tx.value,
+            input: tx.input.clone(),
+            access_list: tx.access_list.clone(),
+        }
+    }
+}
@@ -7,7 +7,7 @@ use revm_primitives::{keccak256, TxEnv};
 use super::kind_to_transact_to;
 use crate::{
     signature::{Signature, SignatureError},
-    transaction::{self, fake_signature::recover_fake_signature, TxKind},
+    transaction::{self, verified::VerifyTransaction, TxKind},
     Address, Bytes, B256, U256,
 };
 
@@ -29,6 +29,11 @@ pub struct Eip155 {
     #[rlp(skip)]
     #[cfg_attr(feature = "serde", serde(skip))]
     pub hash: OnceLock<B256>,
+    /// Cached recovered sender
+    #[rlp(default)]
+    #[rlp(skip)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub sender: OnceLock<Address>,
     /// Whether the signed transaction is from an impersonated account.
     #[rlp(default)]
     #[rlp(skip)]
@@ -42,12 +47,27 @@ impl Eip155 {
     }
 
     /// Recovers the Ethereum address which was used to sign the transaction.
+    ///
+    /// The recovered address is cached, so repeated calls only pay the cost
+    /// of signature recovery once. Internally goes through
+    /// [`VerifyTransaction::verify`], so the recovery logic itself lives in
+    /// one place shared with callers that want the [`VerifiedTransaction`]
+    /// typestate directly.
+    ///
+    /// [`VerifiedTransaction`]: transaction::verified::VerifiedTransaction
     pub fn recover(&self) -> Result<Address, SignatureError> {
-        if self.is_fake {
-            return Ok(recover_fake_signature(&self.signature));
+        if let Some(sender) = self.sender.get() {
+            return Ok(*sender);
         }
-        self.signature
-            .recover(transaction::request::Eip155::from(self).hash())
+
+        let verified = self.clone().verify()?;
+        let sender = *verified.sender();
+
+        self.sender
+            .set(sender)
+            .expect("We checked that the sender is not set");
+
+        Ok(sender)
     }
 
     pub fn chain_id(&self) -> u64 {
@@ -87,6 +107,7 @@ impl From<transaction::signed::legacy::Legacy> for Eip155 {
             input: tx.input,
             signature: tx.signature,
             hash: tx.hash,
+            sender: OnceLock::new(),
             is_fake: tx.is_fake,
         }
     }
@@ -0,0 +1,217 @@
+use std::sync::OnceLock;
+
+use alloy_rlp::{RlpDecodable, RlpEncodable};
+use hashbrown::HashMap;
+use revm_primitives::{keccak256, TxEnv};
+
+use super::kind_to_transact_to;
+use crate::{
+    access_list::AccessList,
+    signature::{self, Fakeable},
+    transaction::{self, request::eip2930::TYPE, TxKind},
+    utils::envelop_bytes,
+    Address, Bytes, B256, U256,
+};
+
+/// An [EIP-2930] access-list transaction. Its RLP encoding is the payload
+/// `rlp([chain_id, nonce, gas_price, gas_limit, to, value, data, access_list,
+/// y_parity, r, s])`; the [EIP-2718] typed envelope `0x01 || payload` is what
+/// [`Eip2930::hash`] hashes and what `TypedTransaction` encodes.
+///
+/// Unlike [`super::Eip155`], `chain_id` is a standalone field and the
+/// signature's `y_parity` is a plain `0`/`1` value, with no `+35` offset.
+///
+/// [EIP-2718]: https://eips.ethereum.org/EIPS/eip-2718
+/// [EIP-2930]: https://eips.ethereum.org/EIPS/eip-2930
+#[derive(Clone, Debug, Eq, RlpEncodable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Eip2930 {
+    // The order of these fields determines de-/encoding order.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde::u64"))]
+    pub chain_id: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde::u64"))]
+    pub nonce: u64,
+    pub gas_price: U256,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde::u64"))]
+    pub gas_limit: u64,
+    pub kind: TxKind,
+    pub value: U256,
+    pub input: Bytes,
+    pub access_list: AccessList,
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub signature: Fakeable<signature::SignatureWithYParity>,
+    /// Cached transaction hash
+    #[rlp(default)]
+    #[rlp(skip)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub hash: OnceLock<B256>,
+}
+
+impl Eip2930 {
+    /// Returns the caller/signer of the transaction.
+    pub fn caller(&self) -> &Address {
+        self.signature.caller()
+    }
+
+    /// Computes the hash of the typed transaction envelope.
+    pub fn hash(&self) -> &B256 {
+        self.hash.get_or_init(|| {
+            let encoded = alloy_rlp::encode(self);
+            let enveloped = envelop_bytes(TYPE, &encoded);
+
+            keccak256(enveloped)
+        })
+    }
+
+    /// Converts this transaction into a `TxEnv`.
+    pub fn into_tx_env(self, caller: Address) -> TxEnv {
+        TxEnv {
+            caller,
+            gas_limit: self.gas_limit,
+            gas_price: self.gas_price,
+            transact_to: kind_to_transact_to(self.kind),
+            value: self.value,
+            data: self.input,
+            nonce: Some(self.nonce),
+            chain_id: Some(self.chain_id),
+            access_list: self.access_list.into(),
+            gas_priority_fee: None,
+            blob_hashes: Vec::new(),
+            max_fee_per_blob_gas: None,
+            eof_initcodes: Vec::new(),
+            eof_initcodes_hashed: HashMap::new(),
+        }
+    }
+}
+
+impl PartialEq for Eip2930 {
+    fn eq(&self, other: &Self) -> bool {
+        self.chain_id == other.chain_id
+            && self.nonce == other.nonce
+            && self.gas_price == other.gas_price
+            && self.gas_limit == other.gas_limit
+            && self.kind == other.kind
+            && self.value == other.value
+            && self.input == other.input
+            && self.access_list == other.access_list
+            && self.signature == other.signature
+    }
+}
+
+/// The RLP-decodable shape of an [`Eip2930`] transaction, used only to parse
+/// the wire format before the real envelope hash (needed to recover and
+/// cache the sender) can be computed.
+#[derive(RlpDecodable)]
+struct Decodable {
+    // The order of these fields determines decoding order.
+    pub chain_id: u64,
+    pub nonce: u64,
+    pub gas_price: U256,
+    pub gas_limit: u64,
+    pub kind: TxKind,
+    pub value: U256,
+    pub input: Bytes,
+    pub access_list: AccessList,
+    pub signature: signature::SignatureWithYParity,
+}
+
+impl alloy_rlp::Decodable for Eip2930 {
+    fn decode(buf: &mut &[u8]) -> alloy_rlp::Result<Self> {
+        let transaction = Decodable::decode(buf)?;
+        let request = transaction::request::Eip2930::from(&transaction);
+
+        let signature = Fakeable::recover(transaction.signature, request.hash().into())
+            .map_err(|_error| alloy_rlp::Error::Custom("Invalid Signature"))?;
+
+        Ok(Self {
+            chain_id: transaction.chain_id,
+            nonce: transaction.nonce,
+            gas_price: transaction.gas_price,
+            gas_limit: transaction.gas_limit,
+            kind: transaction.kind,
+            value: transaction.value,
+            input: transaction.input,
+            access_list: transaction.access_list,
+            signature,
+            hash: OnceLock::new(),
+        })
+    }
+}
+
+impl From<&Decodable> for transaction::request::Eip2930 {
+    fn from(value: &Decodable) -> Self {
+        Self {
+            chain_id: value.chain_id,
+            nonce: value.nonce,
+            gas_price: value.gas_price,
+            gas_limit: value.gas_limit,
+            kind: value.kind,
+            value: value.value,
+            input: value.input.clone(),
+            access_list: value.access_list.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use alloy_rlp::Decodable as _;
+    use k256::SecretKey;
+
+    use super::*;
+    use crate::signature::secret_key_from_str;
+
+    fn dummy_request() -> transaction::request::Eip2930 {
+        let to = Address::from_str("0xc014ba5ec014ba5ec014ba5ec014ba5ec014ba5e").unwrap();
+        let input = hex::decode("1234").unwrap();
+
+        transaction::request::Eip2930 {
+            chain_id: 1,
+            nonce: 1,
+            gas_price: U256::from(2),
+            gas_limit: 3,
+            kind: TxKind::Call(to),
+            value: U256::from(4),
+            input: Bytes::from(input),
+            access_list: Vec::new(),
+        }
+    }
+
+    fn dummy_secret_key() -> SecretKey {
+        secret_key_from_str("e331b6d69882b4cb4ea581d88e0b604039a3de5967688d3dcffdd2270c0fd109")
+            .unwrap()
+    }
+
+    #[test]
+    fn eip2930_signed_transaction_rlp_roundtrip() {
+        let request = dummy_request();
+        let signed = request.sign(&dummy_secret_key()).unwrap();
+
+        let encoded = alloy_rlp::encode(&signed);
+        let decoded = Eip2930::decode(&mut encoded.as_slice()).unwrap();
+
+        assert_eq!(signed, decoded);
+        assert_eq!(signed.hash(), decoded.hash());
+        assert_eq!(signed.caller(), decoded.caller());
+    }
+
+    #[test]
+    fn eip2930_signed_transaction_recovers_same_signer_each_time() {
+        let secret_key = dummy_secret_key();
+
+        let first = dummy_request().sign(&secret_key).unwrap();
+        let second = dummy_request().sign(&secret_key).unwrap();
+
+        assert_eq!(first.caller(), second.caller());
+    }
+
+    #[test]
+    fn eip2930_fake_sign_uses_provided_address() {
+        let address = Address::from_str("0x0000000000000000000000000000000000000123").unwrap();
+
+        let signed = dummy_request().fake_sign(&address);
+        assert_eq!(*signed.caller(), address);
+    }
+}
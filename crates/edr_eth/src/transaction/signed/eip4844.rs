@@ -61,23 +61,30 @@ impl Eip4844 {
     pub fn total_blob_gas(&self) -> u64 {
         GAS_PER_BLOB * self.blob_hashes.len() as u64
     }
-}
 
-impl From<Eip4844> for TxEnv {
-    fn from(value: Eip4844) -> Self {
-        Self {
-            caller: *value.caller(),
-            gas_limit: value.gas_limit,
-            gas_price: value.max_fee_per_gas,
-            transact_to: TransactTo::Call(value.to),
-            value: value.value,
-            data: value.input,
-            nonce: Some(value.nonce),
-            chain_id: Some(value.chain_id),
-            access_list: value.access_list.into(),
-            gas_priority_fee: Some(value.max_priority_fee_per_gas),
-            blob_hashes: value.blob_hashes,
-            max_fee_per_blob_gas: Some(value.max_fee_per_blob_gas),
+    /// Converts this transaction into a `TxEnv`, using `base_fee` (the block
+    /// base fee per gas it executes against) to compute the effective gas
+    /// price, i.e. `min(max_fee_per_gas, base_fee + max_priority_fee_per_gas)`
+    /// rather than always paying `max_fee_per_gas`.
+    pub fn to_tx_env(&self, base_fee: U256) -> TxEnv {
+        let gas_price = std::cmp::min(
+            self.max_fee_per_gas,
+            base_fee + self.max_priority_fee_per_gas,
+        );
+
+        TxEnv {
+            caller: *self.caller(),
+            gas_limit: self.gas_limit,
+            gas_price,
+            transact_to: TransactTo::Call(self.to),
+            value: self.value,
+            data: self.input.clone(),
+            nonce: Some(self.nonce),
+            chain_id: Some(self.chain_id),
+            access_list: self.access_list.clone().into(),
+            gas_priority_fee: Some(self.max_priority_fee_per_gas),
+            blob_hashes: self.blob_hashes.clone(),
+            max_fee_per_blob_gas: Some(self.max_fee_per_blob_gas),
         }
     }
 }
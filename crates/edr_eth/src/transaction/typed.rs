@@ -0,0 +1,217 @@
+//! A single, dispatching [`alloy_rlp::Decodable`]/[`alloy_rlp::Encodable`]
+//! implementation for all [EIP-2718] transaction envelopes this crate
+//! understands, plus pre-EIP-2718 legacy transactions.
+//!
+//! [EIP-2718]: https://eips.ethereum.org/EIPS/eip-2718
+
+use alloy_rlp::{BufMut, Decodable, Encodable};
+
+use super::signed;
+use crate::B256;
+
+/// The [EIP-2718] transaction type identifier.
+///
+/// [EIP-2718]: https://eips.ethereum.org/EIPS/eip-2718
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TransactionType {
+    /// A pre-EIP-2718 legacy transaction.
+    Legacy,
+    /// An EIP-2930 access-list transaction.
+    Eip2930,
+    /// An EIP-4844 blob transaction.
+    Eip4844,
+}
+
+const EIP2930_TYPE: u8 = 0x01;
+const EIP4844_TYPE: u8 = 0x03;
+
+impl From<TransactionType> for u8 {
+    fn from(value: TransactionType) -> Self {
+        match value {
+            TransactionType::Legacy => 0,
+            TransactionType::Eip2930 => EIP2930_TYPE,
+            TransactionType::Eip4844 => EIP4844_TYPE,
+        }
+    }
+}
+
+/// A transaction decoded off the wire: either a pre-EIP-2718 legacy
+/// transaction, or the typed payload of one of the [EIP-2718] envelopes this
+/// crate supports.
+///
+/// Note: this only covers the transaction types this crate currently
+/// implements (`Legacy`, `Eip2930`, `Eip4844`). An `Eip1559` variant should be
+/// added here once that transaction type exists.
+///
+/// [EIP-2718]: https://eips.ethereum.org/EIPS/eip-2718
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TypedTransaction {
+    /// A legacy transaction.
+    Legacy(signed::legacy::Legacy),
+    /// An EIP-2930 access-list transaction.
+    Eip2930(signed::Eip2930),
+    /// An EIP-4844 blob transaction.
+    Eip4844(signed::Eip4844),
+}
+
+impl TypedTransaction {
+    /// Returns the [EIP-2718] type of this transaction.
+    ///
+    /// [EIP-2718]: https://eips.ethereum.org/EIPS/eip-2718
+    pub fn transaction_type(&self) -> TransactionType {
+        match self {
+            Self::Legacy(_) => TransactionType::Legacy,
+            Self::Eip2930(_) => TransactionType::Eip2930,
+            Self::Eip4844(_) => TransactionType::Eip4844,
+        }
+    }
+
+    /// Computes the hash of the transaction.
+    pub fn hash(&self) -> B256 {
+        match self {
+            Self::Legacy(tx) => *tx.hash(),
+            Self::Eip2930(tx) => *tx.hash(),
+            Self::Eip4844(tx) => *tx.hash(),
+        }
+    }
+}
+
+impl Encodable for TypedTransaction {
+    fn length(&self) -> usize {
+        match self {
+            Self::Legacy(tx) => tx.length(),
+            Self::Eip2930(tx) => 1 + tx.length(),
+            Self::Eip4844(tx) => 1 + tx.length(),
+        }
+    }
+
+    fn encode(&self, out: &mut dyn BufMut) {
+        match self {
+            Self::Legacy(tx) => tx.encode(out),
+            Self::Eip2930(tx) => {
+                out.put_u8(EIP2930_TYPE);
+                tx.encode(out);
+            }
+            Self::Eip4844(tx) => {
+                out.put_u8(EIP4844_TYPE);
+                tx.encode(out);
+            }
+        }
+    }
+}
+
+impl Decodable for TypedTransaction {
+    fn decode(buf: &mut &[u8]) -> alloy_rlp::Result<Self> {
+        let first = *buf.first().ok_or(alloy_rlp::Error::InputTooShort)?;
+
+        // A legacy transaction's RLP encoding is always a list, whose header
+        // byte is `>= 0xc0`. Anything below that is an EIP-2718 type byte.
+        if first >= 0xc0 {
+            return signed::legacy::Legacy::decode(buf).map(Self::Legacy);
+        }
+
+        *buf = &buf[1..];
+        match first {
+            EIP2930_TYPE => signed::Eip2930::decode(buf).map(Self::Eip2930),
+            EIP4844_TYPE => signed::Eip4844::decode(buf).map(Self::Eip4844),
+            _ => Err(alloy_rlp::Error::Custom("Unknown transaction type")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use k256::SecretKey;
+
+    use super::*;
+    use crate::{signature::secret_key_from_str, transaction, Address, Bytes, TxKind, U256};
+
+    fn dummy_secret_key() -> SecretKey {
+        secret_key_from_str("e331b6d69882b4cb4ea581d88e0b604039a3de5967688d3dcffdd2270c0fd109")
+            .unwrap()
+    }
+
+    fn dummy_to() -> Address {
+        Address::from_str("0xc014ba5ec014ba5ec014ba5ec014ba5ec014ba5e").unwrap()
+    }
+
+    fn legacy_transaction() -> TypedTransaction {
+        let request = transaction::request::Legacy {
+            nonce: 1,
+            gas_price: U256::from(2),
+            gas_limit: 3,
+            kind: TxKind::Call(dummy_to()),
+            value: U256::from(4),
+            input: Bytes::from(hex::decode("1234").unwrap()),
+        };
+
+        TypedTransaction::Legacy(request.sign(&dummy_secret_key()).unwrap())
+    }
+
+    fn eip2930_transaction() -> TypedTransaction {
+        let request = transaction::request::Eip2930 {
+            chain_id: 1,
+            nonce: 1,
+            gas_price: U256::from(2),
+            gas_limit: 3,
+            kind: TxKind::Call(dummy_to()),
+            value: U256::from(4),
+            input: Bytes::from(hex::decode("1234").unwrap()),
+            access_list: Vec::new(),
+        };
+
+        TypedTransaction::Eip2930(request.sign(&dummy_secret_key()).unwrap())
+    }
+
+    fn each_transaction_type() -> Vec<TypedTransaction> {
+        vec![legacy_transaction(), eip2930_transaction()]
+    }
+
+    #[test]
+    fn transaction_type_matches_the_variant() {
+        assert_eq!(
+            legacy_transaction().transaction_type(),
+            TransactionType::Legacy
+        );
+        assert_eq!(
+            eip2930_transaction().transaction_type(),
+            TransactionType::Eip2930
+        );
+    }
+
+    #[test]
+    fn rlp_roundtrip_preserves_transaction_and_hash() {
+        for transaction in each_transaction_type() {
+            let encoded = alloy_rlp::encode(&transaction);
+            let decoded = TypedTransaction::decode(&mut encoded.as_slice()).unwrap();
+
+            assert_eq!(transaction, decoded);
+            assert_eq!(transaction.hash(), decoded.hash());
+        }
+    }
+
+    #[test]
+    fn legacy_encoding_has_no_type_byte_prefix() {
+        // A legacy transaction's RLP encoding is a bare list, whose header
+        // byte is `>= 0xc0`, unlike the EIP-2718 typed variants.
+        let encoded = alloy_rlp::encode(&legacy_transaction());
+
+        assert!(encoded[0] >= 0xc0);
+    }
+
+    #[test]
+    fn eip2930_encoding_is_prefixed_with_its_type_byte() {
+        let encoded = alloy_rlp::encode(&eip2930_transaction());
+
+        assert_eq!(encoded[0], EIP2930_TYPE);
+    }
+
+    #[test]
+    fn decode_rejects_unknown_type_byte() {
+        let encoded = vec![0x02];
+
+        assert!(TypedTransaction::decode(&mut encoded.as_slice()).is_err());
+    }
+}
@@ -0,0 +1,129 @@
+//! A typestate for transactions whose signer has already been checked.
+//!
+//! Parsing a transaction off the wire yields an unverified envelope; calling
+//! [`VerifyTransaction::verify`] transitions it into a
+//! [`VerifiedTransaction`], which exposes an infallible
+//! [`VerifiedTransaction::sender`]. This mirrors OpenEthereum's split between
+//! an unverified transaction and a signature-checked one, and lets the EVM
+//! loop hold the verified form so it never pays for redundant signature
+//! recovery.
+
+use crate::{
+    transaction::{self, fake_signature::recover_fake_signature},
+    Address,
+};
+
+/// A transaction envelope whose signer has not yet been verified.
+pub trait VerifyTransaction: Sized {
+    /// The error that can occur while recovering the sender.
+    type Error;
+
+    /// Checks the transaction's signature, returning a [`VerifiedTransaction`]
+    /// that caches the recovered sender.
+    fn verify(self) -> Result<VerifiedTransaction<Self>, Self::Error>;
+}
+
+/// A transaction whose sender has already been recovered and cached.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VerifiedTransaction<T> {
+    transaction: T,
+    sender: Address,
+}
+
+impl<T> VerifiedTransaction<T> {
+    /// Wraps an already-verified `transaction` together with its `sender`.
+    pub(crate) fn new(transaction: T, sender: Address) -> Self {
+        Self { transaction, sender }
+    }
+
+    /// Returns the address that signed the transaction. Infallible, since the
+    /// signature was already checked when this value was constructed.
+    pub fn sender(&self) -> &Address {
+        &self.sender
+    }
+
+    /// Returns a reference to the wrapped transaction.
+    pub fn as_inner(&self) -> &T {
+        &self.transaction
+    }
+
+    /// Unwraps this value, discarding the cached sender.
+    pub fn into_inner(self) -> T {
+        self.transaction
+    }
+}
+
+impl VerifyTransaction for super::signed::Eip155 {
+    type Error = crate::signature::SignatureError;
+
+    fn verify(self) -> Result<VerifiedTransaction<Self>, Self::Error> {
+        let sender = if self.is_fake {
+            recover_fake_signature(&self.signature)
+        } else {
+            self.signature
+                .recover(transaction::request::Eip155::from(&self).hash())?
+        };
+
+        Ok(VerifiedTransaction::new(self, sender))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use k256::SecretKey;
+
+    use super::*;
+    use crate::{signature::secret_key_from_str, Bytes, TxKind, U256};
+
+    fn dummy_request() -> transaction::request::Eip155 {
+        let to = Address::from_str("0xc014ba5ec014ba5ec014ba5ec014ba5ec014ba5e").unwrap();
+        let input = hex::decode("1234").unwrap();
+        transaction::request::Eip155 {
+            nonce: 1,
+            gas_price: U256::from(2),
+            gas_limit: 3,
+            kind: TxKind::Call(to),
+            value: U256::from(4),
+            input: Bytes::from(input),
+            chain_id: 1,
+        }
+    }
+
+    fn dummy_secret_key() -> SecretKey {
+        secret_key_from_str("e331b6d69882b4cb4ea581d88e0b604039a3de5967688d3dcffdd2270c0fd109")
+            .unwrap()
+    }
+
+    #[test]
+    fn verify_recovers_same_sender_as_recover() {
+        let signed = dummy_request().sign(&dummy_secret_key()).unwrap();
+        let expected_sender = signed.recover().unwrap();
+
+        let verified = signed.verify().unwrap();
+
+        assert_eq!(*verified.sender(), expected_sender);
+    }
+
+    #[test]
+    fn verify_preserves_the_wrapped_transaction() {
+        let signed = dummy_request().sign(&dummy_secret_key()).unwrap();
+        let expected = signed.clone();
+
+        let verified = signed.verify().unwrap();
+
+        assert_eq!(verified.as_inner(), &expected);
+        assert_eq!(verified.into_inner(), expected);
+    }
+
+    #[test]
+    fn verify_of_fake_signed_transaction_returns_its_address() {
+        let address = Address::from_str("0x0000000000000000000000000000000000000123").unwrap();
+        let signed = dummy_request().fake_sign(&address);
+
+        let verified = signed.verify().unwrap();
+
+        assert_eq!(*verified.sender(), address);
+    }
+}
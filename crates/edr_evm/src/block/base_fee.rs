@@ -0,0 +1,26 @@
+//! Helpers for computing the base fee per gas of locally built blocks.
+
+use edr_eth::{block::Header, eip1559, U256};
+
+/// The base fee of the first post-London block, used when `parent` predates
+/// the London hardfork and therefore carries no base fee of its own.
+pub const INITIAL_BASE_FEE: u64 = 1_000_000_000;
+
+/// Computes the base fee per gas of the block that follows `parent`, per
+/// [EIP-1559].
+///
+/// Returns `None` if `parent` predates the London hardfork activation, i.e.
+/// it has no `base_fee_per_gas` of its own. Callers building the first
+/// post-London block should use [`INITIAL_BASE_FEE`] directly instead of
+/// calling this function.
+///
+/// [EIP-1559]: https://eips.ethereum.org/EIPS/eip-1559
+pub fn calculate_next_base_fee(parent: &Header) -> Option<U256> {
+    let parent_base_fee = parent.base_fee_per_gas?;
+
+    Some(eip1559::calculate_next_base_fee(
+        parent_base_fee,
+        parent.gas_used,
+        parent.gas_limit,
+    ))
+}
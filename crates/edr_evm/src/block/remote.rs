@@ -10,6 +10,7 @@ use edr_eth::{
 use edr_rpc_eth::{client::EthRpcClient, spec::EthRpcSpec};
 use tokio::runtime;
 
+use super::transaction_index::RemoteTransactionIndex;
 use crate::{
     blockchain::{BlockchainError, ForkedBlockchainError},
     chain_spec::L1ChainSpec,
@@ -57,14 +58,43 @@ pub struct RemoteBlock {
     // The RPC client is needed to lazily fetch receipts
     rpc_client: Arc<EthRpcClient<EthRpcSpec>>,
     runtime: runtime::Handle,
+    /// The transaction index of this block. It is shared with the other
+    /// `RemoteBlock`s that were given the same instance through
+    /// [`RemoteBlock::with_transaction_index`]; [`RemoteBlock::new`] creates
+    /// an index of its own.
+    transaction_index: Arc<RemoteTransactionIndex>,
 }
 
 impl RemoteBlock {
     /// Constructs a new instance with the provided JSON-RPC block and client.
+    ///
+    /// This gives the block its own, unshared [`RemoteTransactionIndex`]. A
+    /// caller that constructs many `RemoteBlock`s from the same fork and
+    /// wants their transaction-index lookups to actually accumulate in one
+    /// cache should use [`RemoteBlock::with_transaction_index`] instead,
+    /// passing the same shared instance to every block it builds.
     pub fn new(
         block: edr_rpc_eth::Block<edr_rpc_eth::Transaction>,
         rpc_client: Arc<EthRpcClient<EthRpcSpec>>,
         runtime: runtime::Handle,
+    ) -> Result<Self, CreationError> {
+        let transaction_index = Arc::new(RemoteTransactionIndex::new(
+            rpc_client.clone(),
+            runtime.clone(),
+        ));
+
+        Self::with_transaction_index(block, rpc_client, runtime, transaction_index)
+    }
+
+    /// Like [`RemoteBlock::new`], but reusing a [`RemoteTransactionIndex`]
+    /// already shared by other `RemoteBlock`s from the same fork, so that
+    /// lookups accumulate in one cache instead of starting from empty for
+    /// every block.
+    pub fn with_transaction_index(
+        block: edr_rpc_eth::Block<edr_rpc_eth::Transaction>,
+        rpc_client: Arc<EthRpcClient<EthRpcSpec>>,
+        runtime: runtime::Handle,
+        transaction_index: Arc<RemoteTransactionIndex>,
     ) -> Result<Self, CreationError> {
         let header = Header {
             parent_hash: block.parent_hash,
@@ -111,6 +141,7 @@ impl RemoteBlock {
             rpc_client,
             size: block.size,
             runtime,
+            transaction_index,
         })
     }
 }
@@ -172,6 +203,17 @@ impl Block for RemoteBlock {
     }
 }
 
+impl RemoteBlock {
+    /// Returns this block's [`RemoteTransactionIndex`], which resolves a
+    /// transaction hash to its containing block and position without eagerly
+    /// downloading whole blocks. The index is shared only with the blocks
+    /// built from the same instance through
+    /// [`RemoteBlock::with_transaction_index`].
+    pub fn transaction_index(&self) -> &Arc<RemoteTransactionIndex> {
+        &self.transaction_index
+    }
+}
+
 impl From<RemoteBlock> for Arc<dyn SyncBlock<Error = BlockchainError>> {
     fn from(value: RemoteBlock) -> Self {
         Arc::new(value)
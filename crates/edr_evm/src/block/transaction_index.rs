@@ -0,0 +1,95 @@
+//! A light-protocol-style transaction index: given a transaction hash,
+//! lazily answers "which block and position holds it" without requiring the
+//! whole block to have been downloaded first.
+
+use std::sync::{Arc, Mutex};
+
+use edr_eth::B256;
+use edr_rpc_eth::{client::EthRpcClient, spec::EthRpcSpec};
+use hashbrown::HashMap;
+use tokio::runtime;
+
+use crate::blockchain::ForkedBlockchainError;
+
+/// The block and position of a remote transaction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TransactionIndex {
+    /// The hash of the block containing the transaction.
+    pub block_hash: B256,
+    /// The number of the block containing the transaction.
+    pub block_number: u64,
+    /// The transaction's position within the block.
+    pub index: u64,
+}
+
+/// Resolves transaction hashes to their containing block and position by
+/// lazily querying `eth_getTransactionByHash`, caching results so that
+/// repeated lookups of the same hash don't re-query the remote node.
+///
+/// This mirrors the `TransactionIndex` request light servers use to let
+/// clients locate remote transactions without downloading whole blocks.
+pub struct RemoteTransactionIndex {
+    rpc_client: Arc<EthRpcClient<EthRpcSpec>>,
+    runtime: runtime::Handle,
+    cache: Mutex<HashMap<B256, TransactionIndex>>,
+}
+
+impl RemoteTransactionIndex {
+    /// Constructs a new, empty transaction index backed by `rpc_client`.
+    pub fn new(rpc_client: Arc<EthRpcClient<EthRpcSpec>>, runtime: runtime::Handle) -> Self {
+        Self {
+            rpc_client,
+            runtime,
+            cache: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Returns the block and position of the transaction with the given
+    /// hash, or `None` if the remote node doesn't know about it or it hasn't
+    /// been mined yet.
+    pub fn transaction_index(
+        &self,
+        transaction_hash: B256,
+    ) -> Result<Option<TransactionIndex>, ForkedBlockchainError> {
+        if let Some(index) = self
+            .cache
+            .lock()
+            .expect("the cache lock is never held across an await point")
+            .get(&transaction_hash)
+        {
+            return Ok(Some(*index));
+        }
+
+        let transaction = tokio::task::block_in_place(|| {
+            self.runtime
+                .block_on(self.rpc_client.get_transaction_by_hash(transaction_hash))
+        })
+        .map_err(ForkedBlockchainError::RpcClient)?;
+
+        let Some(transaction) = transaction else {
+            return Ok(None);
+        };
+
+        // Only a mined transaction has a block hash, block number and position.
+        let (Some(block_hash), Some(block_number), Some(index)) = (
+            transaction.block_hash,
+            transaction.block_number,
+            transaction.transaction_index,
+        ) else {
+            return Ok(None);
+        };
+
+        let index = TransactionIndex {
+            block_hash,
+            block_number,
+            index,
+        };
+
+        self.cache
+            .lock()
+            .expect("the cache lock is never held across an await point")
+            .insert(transaction_hash, index);
+
+        Ok(Some(index))
+    }
+}
@@ -134,51 +134,87 @@ pub struct TracingStep {
     pub memory: Option<Buffer>,
 }
 
+/// Options controlling which parts of a [`RawTrace`] are captured by
+/// [`RawTrace::trace`]. Unlike [`RawTrace::old_trace`], which always returns
+/// the full-fidelity step stream, these let callers trade off completeness
+/// for a smaller payload.
+#[napi(object)]
+#[derive(Clone, Copy, Default)]
+pub struct TraceFilter {
+    /// Whether to omit the `stack` field from captured steps.
+    #[napi(readonly)]
+    pub disable_stack: Option<bool>,
+    /// Whether to omit the `memory` field from captured steps.
+    #[napi(readonly)]
+    pub disable_memory: Option<bool>,
+    /// Whether to capture steps at all. Defaults to `true`.
+    #[napi(readonly)]
+    pub include_steps: Option<bool>,
+    /// When set, only steps whose top-of-stack word is strictly greater than
+    /// this value are included.
+    #[napi(readonly)]
+    pub step_threshold: Option<BigInt>,
+}
+
 impl TracingStep {
     pub fn new(step: &edr_evm::trace::Step) -> Self {
-        let stack = step.stack.full().map_or_else(
-            || {
-                step.stack
-                    .top()
-                    .map(u256_to_bigint)
-                    .map_or_else(Vec::default, |top| vec![top])
-            },
-            |stack| stack.iter().map(u256_to_bigint).collect(),
-        );
-        let memory = step.memory.as_ref().cloned().map(Buffer::from);
+        Self::with_filter(step, &TraceFilter::default())
+            .expect("the default filter never excludes a step")
+    }
 
-        Self {
+    /// Builds a [`TracingStep`] from `step`, honoring `filter`. Returns
+    /// `None` if `filter` excludes this step from the trace entirely.
+    pub fn with_filter(step: &edr_evm::trace::Step, filter: &TraceFilter) -> Option<Self> {
+        if filter.include_steps == Some(false) {
+            return None;
+        }
+
+        if let Some(threshold) = &filter.step_threshold {
+            let passes = step
+                .stack
+                .top()
+                .map(|top| {
+                    // A negative threshold admits every step; one that doesn't
+                    // fit in 256 bits admits none.
+                    threshold.sign_bit
+                        || revm_primitives::U256::checked_from_limbs_slice(&threshold.words)
+                            .map_or(false, |threshold| *top > threshold)
+                })
+                .unwrap_or(false);
+
+            if !passes {
+                return None;
+            }
+        }
+
+        let stack = if filter.disable_stack == Some(true) {
+            Vec::new()
+        } else {
+            step.stack.full().map_or_else(
+                || {
+                    step.stack
+                        .top()
+                        .map(u256_to_bigint)
+                        .map_or_else(Vec::default, |top| vec![top])
+                },
+                |stack| stack.iter().map(u256_to_bigint).collect(),
+            )
+        };
+
+        let memory = if filter.disable_memory == Some(true) {
+            None
+        } else {
+            step.memory.as_ref().cloned().map(Buffer::from)
+        };
+
+        Some(Self {
             depth: step.depth as u8,
             pc: BigInt::from(step.pc),
             opcode: OpCode::name_by_op(step.opcode).to_string(),
             stack,
             memory,
-        }
+        })
     }
-
-    // Function to check if the top of the stack does not look like a valid hash
-    pub fn is_valid(step: &edr_evm::trace::Step) -> bool {
-        let stack = step.stack.full().map_or_else(
-            || {
-                // Only get the top element as the fallback if the full stack is not available
-                step.stack.top().map(u256_to_bigint)
-            },
-            |stack| {
-                // Return the last element of the stack if it's fully available
-                stack.last().map(u256_to_bigint)
-            },
-        );
-        // Check if we have a BigInt (unwrap the Option)
-        if let Some(top_element) = stack {
-            // Call get_i64 on the BigInt to extract the value
-            let (value, _sign) = top_element.get_i64();
-            // Check if the top element is greater than 1M
-            value > 1024*1024
-        } else {
-            // Return false if no stack element is present
-            false
-        }
-    }    
 }
 
 fn u256_to_bigint(v: &edr_evm::U256) -> BigInt {
@@ -188,6 +224,23 @@ fn u256_to_bigint(v: &edr_evm::U256) -> BigInt {
     }
 }
 
+// `TracingStep::with_filter` is not tested here; only `TraceFilter`'s
+// defaults are covered below.
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trace_filter_default_has_no_restrictions() {
+        let filter = TraceFilter::default();
+
+        assert_eq!(filter.disable_stack, None);
+        assert_eq!(filter.disable_memory, None);
+        assert_eq!(filter.include_steps, None);
+        assert!(filter.step_threshold.is_none());
+    }
+}
+
 #[napi(object)]
 pub struct TracingMessageResult {
     /// Execution result
@@ -231,37 +284,31 @@ impl RawTrace {
     pub fn trace(
         &self,
         env: Env,
+        filter: Option<TraceFilter>,
     ) -> napi::Result<Vec<Either3<TracingMessage, TracingStep, TracingMessageResult>>> {
-        // Pre-allocate the vector with a known capacity to avoid reallocations
+        let filter = filter.unwrap_or_default();
+
         let mut result_vec = Vec::with_capacity(self.inner.messages.len());
 
         for message in &self.inner.messages {
             let either = match message {
                 edr_evm::trace::TraceMessage::Before(message) => {
-                    // Directly handle the result of TracingMessage::new, avoid extra map calls
-                    match TracingMessage::new(&env, message) {
-                        Ok(tracing_message) => Either3::A(tracing_message),
-                        Err(e) => return Err(e), // Propagate error immediately
-                    }
+                    Either3::A(TracingMessage::new(&env, message)?)
                 }
                 edr_evm::trace::TraceMessage::Step(step) => {
-                    // Check if the stack has elements and test the top element of the stack
-                    if TracingStep::is_valid(step) {
-                       Either3::B(TracingStep::new(step))
-                    } else {
-                        continue; // Skip if the step is not valid
+                    match TracingStep::with_filter(step, &filter) {
+                        Some(tracing_step) => Either3::B(tracing_step),
+                        None => continue,
                     }
                 }
                 edr_evm::trace::TraceMessage::After(message) => {
-                    // Directly handle ExecutionResult, similar to Before case
-                    match ExecutionResult::new(&env, message) {
-                        Ok(execution_result) => Either3::C(TracingMessageResult { execution_result }),
-                        Err(e) => return Err(e), // Propagate error immediately
-                    }
+                    Either3::C(TracingMessageResult {
+                        execution_result: ExecutionResult::new(&env, message)?,
+                    })
                 }
             };
-            result_vec.push(either); // Push directly into the pre-allocated vector
+            result_vec.push(either);
         }
-        Ok(result_vec) // Return the vector at the end
+        Ok(result_vec)
     }
 }
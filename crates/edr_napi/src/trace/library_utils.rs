@@ -1,8 +1,109 @@
 //! Port of the hardhat-network's `library-utils.ts` to Rust.
 
+use std::collections::HashMap;
+
 use edr_evm::hex;
 use napi::bindgen_prelude::Buffer;
 use napi_derive::napi;
+use revm_primitives::{keccak256, Address};
+
+/// Length in hex characters of a library placeholder, i.e. the length of the
+/// 20-byte address slot it stands in for.
+const PLACEHOLDER_LEN: usize = 40;
+
+/// The solc >=0.5.12 library reference placeholder: `__$<34 hex chars>$__`,
+/// where the 34 hex chars are the first 17 bytes of
+/// `keccak256(fully_qualified_library_name)`.
+fn placeholder_by_hash(fully_qualified_name: &str) -> String {
+    let digest = keccak256(fully_qualified_name.as_bytes());
+    format!("__${}$__", hex::encode(&digest[..17]))
+}
+
+/// The older, pre-0.5.12 library reference placeholder: the (possibly
+/// truncated) library name prefixed with `__` and padded with `_` out to
+/// [`PLACEHOLDER_LEN`] characters.
+fn placeholder_by_name(library_name: &str) -> String {
+    let mut placeholder = format!("__{}", library_name.chars().take(36).collect::<String>());
+    while placeholder.len() < PLACEHOLDER_LEN {
+        placeholder.push('_');
+    }
+    placeholder
+}
+
+/// Links compiler output bytecode by substituting solc's library reference
+/// placeholders (both the `__$<34 hex>$__` hash-based form and the older
+/// `__LibraryName____` padded-name form) with the deployed addresses in
+/// `libraries`, keyed by fully qualified library name.
+///
+/// Returns the linked bytecode together with any placeholders found in
+/// `code` that don't have a matching entry in `libraries`.
+pub fn link_bytecode_by_name(
+    mut code: String,
+    libraries: &HashMap<String, Address>,
+) -> (String, Vec<String>) {
+    let mut placeholders = HashMap::with_capacity(libraries.len() * 2);
+    for (name, address) in libraries {
+        placeholders.insert(placeholder_by_hash(name), *address);
+        placeholders.insert(placeholder_by_name(name), *address);
+    }
+
+    let mut unresolved = Vec::new();
+    let mut search_from = 0;
+
+    while let Some(relative_start) = code[search_from..].find("__") {
+        let start = search_from + relative_start;
+        let Some(placeholder) = code.get(start..start + PLACEHOLDER_LEN) else {
+            // Fewer than `PLACEHOLDER_LEN` bytes remain after this `__`, so it
+            // can't be a real placeholder. Skip past it and keep scanning,
+            // rather than abandoning the rest of `code`.
+            search_from = start + 2;
+            continue;
+        };
+
+        if let Some(address) = placeholders.get(placeholder) {
+            let replacement = hex::encode(address.as_slice());
+            code.replace_range(start..start + PLACEHOLDER_LEN, &replacement);
+        } else {
+            unresolved.push(placeholder.to_string());
+        }
+
+        search_from = start + PLACEHOLDER_LEN;
+    }
+
+    (code, unresolved)
+}
+
+/// The result of linking compiler output bytecode by library name.
+#[napi(object)]
+pub struct LinkedBytecode {
+    /// The linked bytecode.
+    pub code: Buffer,
+    /// Any library placeholders found in the input that had no corresponding
+    /// entry in the provided library map.
+    #[napi(js_name = "unresolvedLibraries")]
+    pub unresolved_libraries: Vec<String>,
+}
+
+#[napi(js_name = "linkBytecodeByName")]
+pub fn link_bytecode_by_name_napi(
+    code: String,
+    libraries: HashMap<String, Buffer>,
+) -> napi::Result<LinkedBytecode> {
+    let libraries = libraries
+        .into_iter()
+        .map(|(name, address)| (name, Address::from_slice(&address)))
+        .collect();
+
+    let (code, unresolved_libraries) = link_bytecode_by_name(code, &libraries);
+
+    Ok(LinkedBytecode {
+        code: Buffer::from(
+            hex::decode(code)
+                .map_err(|e| napi::Error::from_reason(format!("Failed to decode hex: {e:?}")))?,
+        ),
+        unresolved_libraries,
+    })
+}
 
 /// Normalizes the compiler output bytecode by replacing the library addresses
 /// with zeros.
@@ -30,3 +131,99 @@ pub fn normalize_compiler_output_bytecode(
 pub fn link_hex_string_bytecode(code: String, address: String, position: u32) -> String {
     edr_solidity::library_utils::link_hex_string_bytecode(code, &address, position)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn placeholder_by_hash_has_expected_shape() {
+        let placeholder = placeholder_by_hash("contracts/Lib.sol:Lib");
+
+        assert_eq!(placeholder.len(), PLACEHOLDER_LEN);
+        assert!(placeholder.starts_with("__$"));
+        assert!(placeholder.ends_with("$__"));
+    }
+
+    #[test]
+    fn placeholder_by_name_pads_to_placeholder_len() {
+        let placeholder = placeholder_by_name("Lib");
+
+        assert_eq!(placeholder.len(), PLACEHOLDER_LEN);
+        assert!(placeholder.starts_with("__Lib"));
+    }
+
+    #[test]
+    fn placeholder_by_name_truncates_long_names() {
+        let long_name = "A".repeat(64);
+        let placeholder = placeholder_by_name(&long_name);
+
+        assert_eq!(placeholder.len(), PLACEHOLDER_LEN);
+    }
+
+    #[test]
+    fn link_bytecode_by_name_resolves_hash_placeholder() {
+        let name = "contracts/Lib.sol:Lib";
+        let address = Address::from_slice(&[0xab; 20]);
+
+        let code = format!("6000{}6000", placeholder_by_hash(name));
+        let mut libraries = HashMap::new();
+        libraries.insert(name.to_string(), address);
+
+        let (linked, unresolved) = link_bytecode_by_name(code, &libraries);
+
+        assert!(unresolved.is_empty());
+        assert_eq!(
+            linked,
+            format!("6000{}6000", hex::encode(address.as_slice()))
+        );
+    }
+
+    #[test]
+    fn link_bytecode_by_name_resolves_name_placeholder() {
+        let name = "Lib";
+        let address = Address::from_slice(&[0xcd; 20]);
+
+        let code = format!("6000{}6000", placeholder_by_name(name));
+        let mut libraries = HashMap::new();
+        libraries.insert(name.to_string(), address);
+
+        let (linked, unresolved) = link_bytecode_by_name(code, &libraries);
+
+        assert!(unresolved.is_empty());
+        assert_eq!(
+            linked,
+            format!("6000{}6000", hex::encode(address.as_slice()))
+        );
+    }
+
+    #[test]
+    fn link_bytecode_by_name_reports_unresolved_placeholder() {
+        let code = format!("6000{}6000", placeholder_by_name("Missing"));
+
+        let (linked, unresolved) = link_bytecode_by_name(code.clone(), &HashMap::new());
+
+        assert_eq!(linked, code);
+        assert_eq!(unresolved, vec![placeholder_by_name("Missing")]);
+    }
+
+    #[test]
+    fn link_bytecode_by_name_handles_trailing_truncated_match() {
+        let name = "Lib";
+        let address = Address::from_slice(&[0xcd; 20]);
+
+        // A real, resolvable placeholder followed by a trailing `__` with
+        // fewer than `PLACEHOLDER_LEN` bytes after it.
+        let code = format!("{}6000__ab", placeholder_by_name(name));
+        let mut libraries = HashMap::new();
+        libraries.insert(name.to_string(), address);
+
+        let (linked, unresolved) = link_bytecode_by_name(code, &libraries);
+
+        assert!(unresolved.is_empty());
+        assert_eq!(
+            linked,
+            format!("{}6000__ab", hex::encode(address.as_slice()))
+        );
+    }
+}
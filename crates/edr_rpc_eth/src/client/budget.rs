@@ -0,0 +1,164 @@
+//! Request-cost budgeting for [`EthRpcClient`](super::EthRpcClient).
+//!
+//! Borrows the credit/cost accounting model used by light-client protocols:
+//! every RPC method has an associated cost, the client holds a replenishing
+//! credit balance, and outgoing requests are throttled once that balance is
+//! exhausted. This protects rate-limited upstreams from unbounded batch
+//! requests, e.g. [`EthRpcClient::get_transaction_receipts`](super::EthRpcClient::get_transaction_receipts).
+
+use std::time::{Duration, Instant};
+
+use tokio::sync::Mutex;
+
+/// The cost, in credits, of a single RPC call.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RequestCost {
+    /// The flat cost charged regardless of batch size.
+    pub base: u32,
+    /// The additional cost charged per item, for batched calls such as
+    /// `eth_getTransactionReceipt` over many hashes.
+    pub per_item: u32,
+}
+
+impl RequestCost {
+    /// A request with a flat cost and no per-item component.
+    pub const fn flat(base: u32) -> Self {
+        Self { base, per_item: 0 }
+    }
+
+    /// The total cost of a request for `num_items` items (1 for a
+    /// non-batched call).
+    pub fn total(&self, num_items: usize) -> u64 {
+        u64::from(self.base) + u64::from(self.per_item) * num_items as u64
+    }
+}
+
+/// Configuration for [`EthRpcClient`](super::EthRpcClient)'s credit budget.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BudgetConfig {
+    /// The maximum number of credits the balance can hold.
+    pub max_credits: u64,
+    /// The number of credits replenished per `refill_interval`.
+    pub refill_amount: u64,
+    /// How often `refill_amount` credits are added back to the balance.
+    pub refill_interval: Duration,
+}
+
+impl Default for BudgetConfig {
+    fn default() -> Self {
+        Self {
+            max_credits: 10_000,
+            refill_amount: 10_000,
+            refill_interval: Duration::from_secs(1),
+        }
+    }
+}
+
+/// The RPC methods that [`EthRpcClient`](super::EthRpcClient) charges
+/// against a [`RequestBudget`], with their [`RequestCost`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RpcMethod {
+    /// `eth_getTransactionByHash`
+    GetTransactionByHash,
+    /// `eth_getTransactionReceipt`, batched over the hashes passed to
+    /// [`EthRpcClient::get_transaction_receipts`](super::EthRpcClient::get_transaction_receipts).
+    GetTransactionReceipts,
+}
+
+impl RpcMethod {
+    /// The [`RequestCost`] charged for a call to this method.
+    pub const fn cost(self) -> RequestCost {
+        match self {
+            Self::GetTransactionByHash => RequestCost::flat(1),
+            Self::GetTransactionReceipts => RequestCost {
+                base: 1,
+                per_item: 1,
+            },
+        }
+    }
+}
+
+/// Error returned when a request cannot be admitted under the current
+/// budget.
+#[derive(Debug, thiserror::Error)]
+pub enum BudgetError {
+    /// The request's cost exceeds `max_credits`, so it could never succeed
+    /// even with a full balance.
+    #[error("Request cost of {cost} credits exceeds the maximum balance of {max_credits}")]
+    NotEnoughCredits { cost: u64, max_credits: u64 },
+}
+
+/// A replenishing credit balance that gates outgoing requests.
+pub struct RequestBudget {
+    config: BudgetConfig,
+    state: Mutex<BudgetState>,
+}
+
+struct BudgetState {
+    credits: u64,
+    last_refill: Instant,
+}
+
+impl RequestBudget {
+    /// Constructs a new budget with a full balance.
+    pub fn new(config: BudgetConfig) -> Self {
+        Self {
+            state: Mutex::new(BudgetState {
+                credits: config.max_credits,
+                last_refill: Instant::now(),
+            }),
+            config,
+        }
+    }
+
+    /// Waits until `method`'s cost for a call over `num_items` items (1 for a
+    /// non-batched call) is available, then deducts it. Convenience wrapper
+    /// around [`RequestBudget::acquire`] using [`RpcMethod::cost`].
+    pub async fn acquire_for(
+        &self,
+        method: RpcMethod,
+        num_items: usize,
+    ) -> Result<(), BudgetError> {
+        self.acquire(method.cost().total(num_items)).await
+    }
+
+    /// Waits until at least `cost` credits are available, then deducts them.
+    ///
+    /// Returns [`BudgetError::NotEnoughCredits`] immediately if `cost` can
+    /// never be satisfied, even by a full balance.
+    pub async fn acquire(&self, cost: u64) -> Result<(), BudgetError> {
+        if cost > self.config.max_credits {
+            return Err(BudgetError::NotEnoughCredits {
+                cost,
+                max_credits: self.config.max_credits,
+            });
+        }
+
+        loop {
+            let wait = {
+                let mut state = self.state.lock().await;
+                self.refill(&mut state);
+
+                if state.credits >= cost {
+                    state.credits -= cost;
+                    return Ok(());
+                }
+
+                self.config.refill_interval
+            };
+
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    fn refill(&self, state: &mut BudgetState) {
+        let elapsed = state.last_refill.elapsed();
+        let intervals = elapsed.as_secs_f64() / self.config.refill_interval.as_secs_f64();
+
+        if intervals >= 1.0 {
+            let replenished = (intervals as u64).saturating_mul(self.config.refill_amount);
+            state.credits = (state.credits + replenished).min(self.config.max_credits);
+            state.last_refill = Instant::now();
+        }
+    }
+}